@@ -0,0 +1,429 @@
+use crate::{Model, Triangle};
+
+const LEAF_SIZE: usize = 4;
+
+/// An axis-aligned bounding box.
+#[derive(Debug, Clone, Copy)]
+pub struct Aabb {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+
+impl Aabb {
+    pub fn empty() -> Self {
+        Aabb {
+            min: [f32::INFINITY; 3],
+            max: [f32::NEG_INFINITY; 3],
+        }
+    }
+
+    pub fn add_point(&mut self, p: [f32; 3]) {
+        for (axis, &coord) in p.iter().enumerate() {
+            self.min[axis] = self.min[axis].min(coord);
+            self.max[axis] = self.max[axis].max(coord);
+        }
+    }
+
+    pub fn extend(&mut self, other: &Aabb) {
+        self.add_point(other.min);
+        self.add_point(other.max);
+    }
+
+    pub fn centroid(&self) -> [f32; 3] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+            (self.min[2] + self.max[2]) * 0.5,
+        ]
+    }
+
+    fn largest_axis(&self) -> usize {
+        let extent = [
+            self.max[0] - self.min[0],
+            self.max[1] - self.min[1],
+            self.max[2] - self.min[2],
+        ];
+        if extent[0] >= extent[1] && extent[0] >= extent[2] {
+            0
+        } else if extent[1] >= extent[2] {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Slab test. Returns whether the ray enters this box somewhere in the
+    /// distance interval `[t_min, t_max_limit]` along `dir`.
+    fn intersects(&self, origin: [f32; 3], dir: [f32; 3], t_min: f32, t_max_limit: f32) -> bool {
+        let mut tmin = t_min;
+        let mut tmax = t_max_limit;
+        for axis in 0..3 {
+            let inv_d = 1.0 / dir[axis];
+            let mut t1 = (self.min[axis] - origin[axis]) * inv_d;
+            let mut t2 = (self.max[axis] - origin[axis]) * inv_d;
+            if t1 > t2 {
+                std::mem::swap(&mut t1, &mut t2);
+            }
+            tmin = tmin.max(t1);
+            tmax = tmax.min(t2);
+            if tmax < tmin {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// The result of a `Model::raycast` query: the nearest triangle the ray hit.
+#[derive(Debug, Clone, Copy)]
+pub struct Hit {
+    pub distance: f32,
+    /// Barycentric coordinates `(u, v)` of the hit point; the weight on the
+    /// triangle's first vertex is `1.0 - u - v`.
+    pub barycentric: (f32, f32),
+    pub face_index: usize,
+}
+
+struct BvhTriangle {
+    positions: [[f32; 3]; 3],
+    bounds: Aabb,
+    centroid: [f32; 3],
+    face_index: usize,
+}
+
+enum BvhNode {
+    Leaf {
+        bounds: Aabb,
+        triangles: Vec<usize>,
+    },
+    Interior {
+        bounds: Aabb,
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+    },
+}
+
+impl BvhNode {
+    fn bounds(&self) -> &Aabb {
+        match self {
+            BvhNode::Leaf { bounds, .. } => bounds,
+            BvhNode::Interior { bounds, .. } => bounds,
+        }
+    }
+}
+
+/// A binary BVH over a `Model`'s triangulated faces, used to answer ray
+/// intersection queries (picking, occlusion, offline rendering).
+pub struct Bvh {
+    triangles: Vec<BvhTriangle>,
+    root: BvhNode,
+}
+
+fn build_node(items: &mut [usize], triangles: &[BvhTriangle]) -> BvhNode {
+    let mut bounds = Aabb::empty();
+    for &i in items.iter() {
+        bounds.extend(&triangles[i].bounds);
+    }
+
+    if items.len() <= LEAF_SIZE {
+        return BvhNode::Leaf {
+            bounds,
+            triangles: items.to_vec(),
+        };
+    }
+
+    let mut centroid_bounds = Aabb::empty();
+    for &i in items.iter() {
+        centroid_bounds.add_point(triangles[i].centroid);
+    }
+    let axis = centroid_bounds.largest_axis();
+
+    let mid = items.len() / 2;
+    items.select_nth_unstable_by(mid, |&a, &b| {
+        // A vertex whose literal overflows f32 parses as +-inf (see
+        // fastparse::read_float), and `inf + -inf` in Aabb::centroid() is
+        // NaN. partial_cmp on NaN is None, so fall back to Equal: the split
+        // degrades to an arbitrary order instead of panicking on otherwise
+        // valid input.
+        triangles[a].centroid[axis]
+            .partial_cmp(&triangles[b].centroid[axis])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let (left_items, right_items) = items.split_at_mut(mid);
+    let left = build_node(left_items, triangles);
+    let right = build_node(right_items, triangles);
+
+    BvhNode::Interior {
+        bounds,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+/// Möller–Trumbore ray-triangle intersection. Returns `(t, u, v)` on a hit.
+fn intersect_triangle(origin: [f32; 3], dir: [f32; 3], tri: &[[f32; 3]; 3]) -> Option<(f32, f32, f32)> {
+    const EPSILON: f32 = 1e-7;
+
+    let edge1 = sub(tri[1], tri[0]);
+    let edge2 = sub(tri[2], tri[0]);
+    let pvec = cross(dir, edge2);
+    let det = dot(edge1, pvec);
+
+    if det.abs() < EPSILON {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+
+    let tvec = sub(origin, tri[0]);
+    let u = dot(tvec, pvec) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let qvec = cross(tvec, edge1);
+    let v = dot(dir, qvec) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = dot(edge2, qvec) * inv_det;
+    if t <= EPSILON {
+        return None;
+    }
+
+    Some((t, u, v))
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl Bvh {
+    /// Builds a BVH over every triangle produced by `model.triangles()`.
+    pub fn build(model: &Model) -> Self {
+        let triangles: Vec<BvhTriangle> = model
+            .triangles()
+            .into_iter()
+            .map(|tri: Triangle| {
+                let positions = [
+                    vertex(model, tri.vertex_indices[0]),
+                    vertex(model, tri.vertex_indices[1]),
+                    vertex(model, tri.vertex_indices[2]),
+                ];
+                let mut bounds = Aabb::empty();
+                for &p in &positions {
+                    bounds.add_point(p);
+                }
+                BvhTriangle {
+                    centroid: bounds.centroid(),
+                    positions,
+                    bounds,
+                    face_index: tri.face_index,
+                }
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..triangles.len()).collect();
+        let root = build_node(&mut order, &triangles);
+
+        Bvh { triangles, root }
+    }
+
+    /// Casts a ray and returns the nearest triangle it hits, if any.
+    pub fn raycast(&self, origin: [f32; 3], dir: [f32; 3]) -> Option<Hit> {
+        let mut best: Option<Hit> = None;
+        self.raycast_node(&self.root, origin, dir, &mut best);
+        best
+    }
+
+    fn raycast_node(&self, node: &BvhNode, origin: [f32; 3], dir: [f32; 3], best: &mut Option<Hit>) {
+        let t_max = best.map(|h| h.distance).unwrap_or(f32::INFINITY);
+        if !node.bounds().intersects(origin, dir, 0.0, t_max) {
+            return;
+        }
+
+        match node {
+            BvhNode::Leaf { triangles, .. } => {
+                for &i in triangles {
+                    let tri = &self.triangles[i];
+                    if let Some((t, u, v)) = intersect_triangle(origin, dir, &tri.positions) {
+                        if t < best.map(|h| h.distance).unwrap_or(f32::INFINITY) {
+                            *best = Some(Hit {
+                                distance: t,
+                                barycentric: (u, v),
+                                face_index: tri.face_index,
+                            });
+                        }
+                    }
+                }
+            }
+            BvhNode::Interior { left, right, .. } => {
+                self.raycast_node(left, origin, dir, best);
+                self.raycast_node(right, origin, dir, best);
+            }
+        }
+    }
+}
+
+fn vertex(model: &Model, index: usize) -> [f32; 3] {
+    let v = &model.vertices[index];
+    [v.x, v.y, v.z]
+}
+
+impl Model {
+    /// Builds a fresh BVH over this model's geometry and casts a single ray
+    /// against it. For repeated queries against the same model, build a
+    /// `Bvh` once with `Bvh::build` and reuse it instead.
+    pub fn raycast(&self, origin: [f32; 3], dir: [f32; 3]) -> Option<Hit> {
+        Bvh::build(self).raycast(origin, dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Face, NamedRange};
+    use std::collections::HashMap;
+
+    /// A single triangle in the XY plane at z=0, spanning (0,0)-(1,0)-(0,1).
+    fn single_triangle_model() -> Model {
+        Model {
+            vertices: vec![
+                crate::Vertex { x: 0.0, y: 0.0, z: 0.0 },
+                crate::Vertex { x: 1.0, y: 0.0, z: 0.0 },
+                crate::Vertex { x: 0.0, y: 1.0, z: 0.0 },
+            ],
+            normals: Vec::new(),
+            texcoords: Vec::new(),
+            faces: vec![Face {
+                vertex_indices: vec![0, 1, 2],
+                normal_indices: Vec::new(),
+                texture_indices: Vec::new(),
+                material_index: None,
+            }],
+            materials: HashMap::new(),
+            material_names: Vec::new(),
+            mtllibs: Vec::new(),
+            objects: Vec::<NamedRange>::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn raycast_hits_known_triangle() {
+        let model = single_triangle_model();
+
+        let hit = model
+            .raycast([0.2, 0.2, 5.0], [0.0, 0.0, -1.0])
+            .expect("ray straight down through the triangle should hit");
+
+        assert_eq!(hit.face_index, 0);
+        assert!((hit.distance - 5.0).abs() < 1e-4);
+        let (u, v) = hit.barycentric;
+        assert!((0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v));
+    }
+
+    #[test]
+    fn raycast_misses_when_ray_points_away_from_triangle() {
+        let model = single_triangle_model();
+
+        let hit = model.raycast([0.2, 0.2, 5.0], [0.0, 0.0, 1.0]);
+        assert!(hit.is_none());
+    }
+
+    /// 6 disjoint triangles spread along the x-axis, more than `LEAF_SIZE`
+    /// (4), so `Bvh::build` must go through `build_node`'s interior-node
+    /// split path rather than stopping at a single leaf.
+    fn many_triangles_model() -> Model {
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+        for i in 0..6 {
+            let x = i as f32 * 10.0;
+            let base = vertices.len();
+            vertices.push(crate::Vertex { x, y: 0.0, z: 0.0 });
+            vertices.push(crate::Vertex { x: x + 1.0, y: 0.0, z: 0.0 });
+            vertices.push(crate::Vertex { x, y: 1.0, z: 0.0 });
+            faces.push(Face {
+                vertex_indices: vec![base, base + 1, base + 2],
+                normal_indices: Vec::new(),
+                texture_indices: Vec::new(),
+                material_index: None,
+            });
+        }
+        Model {
+            vertices,
+            normals: Vec::new(),
+            texcoords: Vec::new(),
+            faces,
+            materials: HashMap::new(),
+            material_names: Vec::new(),
+            mtllibs: Vec::new(),
+            objects: Vec::<NamedRange>::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn raycast_through_interior_split_hits_the_right_triangle() {
+        let model = many_triangles_model();
+
+        let hit = model
+            .raycast([10.2, 0.2, 5.0], [0.0, 0.0, -1.0])
+            .expect("ray through the 2nd triangle's bounds should hit");
+
+        assert_eq!(hit.face_index, 1);
+    }
+
+    #[test]
+    fn build_node_split_does_not_panic_on_nan_centroid() {
+        // A vertex with a component so large it overflows f32 and becomes
+        // `inf`; paired with an opposite-signed `inf` on the same axis this
+        // makes Aabb::centroid() produce NaN for that triangle, which used
+        // to make the split comparator's `.unwrap()` panic.
+        let mut model = many_triangles_model();
+        model.vertices.push(crate::Vertex { x: f32::INFINITY, y: 0.0, z: 0.0 });
+        model.vertices.push(crate::Vertex { x: 1.0, y: 0.0, z: 0.0 });
+        model.vertices.push(crate::Vertex { x: 0.0, y: 1.0, z: 0.0 });
+        model.vertices.push(crate::Vertex { x: f32::NEG_INFINITY, y: 0.0, z: 0.0 });
+        let base = model.vertices.len() - 4;
+        model.faces.push(Face {
+            vertex_indices: vec![base, base + 3, base + 2],
+            normal_indices: Vec::new(),
+            texture_indices: Vec::new(),
+            material_index: None,
+        });
+        model.faces.push(Face {
+            vertex_indices: vec![base + 3, base + 1, base + 2],
+            normal_indices: Vec::new(),
+            texture_indices: Vec::new(),
+            material_index: None,
+        });
+
+        // Must not panic building the BVH (select_nth_unstable_by over a
+        // NaN centroid) or raycasting against it.
+        let _ = model.raycast([0.0, 0.0, 5.0], [0.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn aabb_slab_test_matches_obvious_hits_and_misses() {
+        let mut bounds = Aabb::empty();
+        bounds.add_point([-1.0, -1.0, -1.0]);
+        bounds.add_point([1.0, 1.0, 1.0]);
+
+        assert!(bounds.intersects([0.0, 0.0, 5.0], [0.0, 0.0, -1.0], 0.0, f32::INFINITY));
+        assert!(!bounds.intersects([5.0, 5.0, 5.0], [0.0, 0.0, -1.0], 0.0, f32::INFINITY));
+    }
+}
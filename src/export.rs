@@ -0,0 +1,214 @@
+//! Writes a parsed `Model` back out as ASCII PLY or OFF, so this crate can
+//! double as a simple format converter.
+
+use std::io::{self, Write};
+
+use crate::{Face, Model};
+
+/// Whether `indices` (a face's `normal_indices` or `texture_indices`) are
+/// consistently the same as its `vertex_indices` across every face in
+/// `faces` — i.e. the normals/texcoords are genuinely indexed in parallel
+/// with vertices, not just coincidentally the same length. A face whose
+/// `indices` are empty is not counted against this.
+fn indices_match_vertices(faces: &[Face], indices: impl Fn(&Face) -> &[usize]) -> bool {
+    faces
+        .iter()
+        .all(|face| indices(face).is_empty() || indices(face) == face.vertex_indices.as_slice())
+}
+
+impl Model {
+    /// Writes this model as ASCII PLY. Faces are written as-is (one
+    /// `<count> i0 i1 ... ik` line per face, same as `write_off`), so a
+    /// model's topology round-trips identically through either writer.
+    /// Per-vertex normals/texcoords are only emitted when every face that
+    /// indexes them does so exactly like its vertices (the common case for a model with true
+    /// per-vertex attributes); otherwise the PLY carries positions only.
+    pub fn write_ply<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let with_normals = self.normals.len() == self.vertices.len()
+            && indices_match_vertices(&self.faces, |f| &f.normal_indices);
+        let with_texcoords = self.texcoords.len() == self.vertices.len()
+            && indices_match_vertices(&self.faces, |f| &f.texture_indices);
+
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format ascii 1.0")?;
+        writeln!(writer, "element vertex {}", self.vertices.len())?;
+        writeln!(writer, "property float x")?;
+        writeln!(writer, "property float y")?;
+        writeln!(writer, "property float z")?;
+        if with_normals {
+            writeln!(writer, "property float nx")?;
+            writeln!(writer, "property float ny")?;
+            writeln!(writer, "property float nz")?;
+        }
+        if with_texcoords {
+            writeln!(writer, "property float s")?;
+            writeln!(writer, "property float t")?;
+        }
+        writeln!(writer, "element face {}", self.faces.len())?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        for (i, v) in self.vertices.iter().enumerate() {
+            write!(writer, "{} {} {}", v.x, v.y, v.z)?;
+            if with_normals {
+                let n = &self.normals[i];
+                write!(writer, " {} {} {}", n.x, n.y, n.z)?;
+            }
+            if with_texcoords {
+                let t = &self.texcoords[i];
+                write!(writer, " {} {}", t.u, t.v)?;
+            }
+            writeln!(writer)?;
+        }
+
+        for face in &self.faces {
+            write!(writer, "{}", face.vertex_indices.len())?;
+            for &idx in &face.vertex_indices {
+                write!(writer, " {}", idx)?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes this model as OFF (`numVertices numFaces numEdges`, followed
+    /// by the vertex block and a face block). Edges aren't tracked by this
+    /// loader, so the edge count is always written as `0`.
+    pub fn write_off<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "OFF")?;
+        writeln!(writer, "{} {} 0", self.vertices.len(), self.faces.len())?;
+
+        for v in &self.vertices {
+            writeln!(writer, "{} {} {}", v.x, v.y, v.z)?;
+        }
+
+        for face in &self.faces {
+            write!(writer, "{}", face.vertex_indices.len())?;
+            for &idx in &face.vertex_indices {
+                write!(writer, " {}", idx)?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NamedRange, Normal, TextureCoord, Vertex};
+    use std::collections::HashMap;
+
+    /// A single quad face (kept as an n-gon, not triangulated) over 4
+    /// vertices, each with a matching normal and texcoord.
+    fn quad_model() -> Model {
+        Model {
+            vertices: vec![
+                Vertex { x: 0.0, y: 0.0, z: 0.0 },
+                Vertex { x: 1.0, y: 0.0, z: 0.0 },
+                Vertex { x: 1.0, y: 1.0, z: 0.0 },
+                Vertex { x: 0.0, y: 1.0, z: 0.0 },
+            ],
+            normals: vec![
+                Normal { x: 0.0, y: 0.0, z: 1.0 },
+                Normal { x: 0.0, y: 0.0, z: 1.0 },
+                Normal { x: 0.0, y: 0.0, z: 1.0 },
+                Normal { x: 0.0, y: 0.0, z: 1.0 },
+            ],
+            texcoords: vec![
+                TextureCoord { u: 0.0, v: 0.0 },
+                TextureCoord { u: 1.0, v: 0.0 },
+                TextureCoord { u: 1.0, v: 1.0 },
+                TextureCoord { u: 0.0, v: 1.0 },
+            ],
+            faces: vec![Face {
+                vertex_indices: vec![0, 1, 2, 3],
+                normal_indices: vec![0, 1, 2, 3],
+                texture_indices: vec![0, 1, 2, 3],
+                material_index: None,
+            }],
+            materials: HashMap::new(),
+            material_names: Vec::new(),
+            mtllibs: Vec::new(),
+            objects: Vec::<NamedRange>::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn write_ply_matches_expected_bytes() {
+        let model = quad_model();
+        let mut out = Vec::new();
+        model.write_ply(&mut out).unwrap();
+
+        let expected = "ply\n\
+format ascii 1.0\n\
+element vertex 4\n\
+property float x\n\
+property float y\n\
+property float z\n\
+property float nx\n\
+property float ny\n\
+property float nz\n\
+property float s\n\
+property float t\n\
+element face 1\n\
+property list uchar int vertex_indices\n\
+end_header\n\
+0 0 0 0 0 1 0 0\n\
+1 0 0 0 0 1 1 0\n\
+1 1 0 0 0 1 1 1\n\
+0 1 0 0 0 1 0 1\n\
+4 0 1 2 3\n";
+
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn write_off_matches_expected_bytes() {
+        let model = quad_model();
+        let mut out = Vec::new();
+        model.write_off(&mut out).unwrap();
+
+        let expected = "OFF\n\
+4 1 0\n\
+0 0 0\n\
+1 0 0\n\
+1 1 0\n\
+0 1 0\n\
+4 0 1 2 3\n";
+
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn ply_and_off_agree_on_face_topology() {
+        let model = quad_model();
+        let mut ply = Vec::new();
+        let mut off = Vec::new();
+        model.write_ply(&mut ply).unwrap();
+        model.write_off(&mut off).unwrap();
+
+        let ply_face_line = String::from_utf8(ply).unwrap().lines().last().unwrap().to_string();
+        let off_face_line = String::from_utf8(off).unwrap().lines().last().unwrap().to_string();
+        assert_eq!(ply_face_line, off_face_line);
+        assert_eq!(ply_face_line, "4 0 1 2 3");
+    }
+
+    #[test]
+    fn write_ply_omits_normals_and_texcoords_when_not_per_vertex() {
+        let mut model = quad_model();
+        // Only 3 normals for 4 vertices: no longer a plausible per-vertex mapping.
+        model.normals.pop();
+        model.texcoords.clear();
+
+        let mut out = Vec::new();
+        model.write_ply(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(!text.contains("property float nx"));
+        assert!(!text.contains("property float s"));
+    }
+}
@@ -0,0 +1,203 @@
+//! A zero-allocation replacement for `split_whitespace`/`split('/')` plus
+//! `str::parse` on the hot `v`/`vn`/`vt`/`f` lines. Large OBJ files spend
+//! most of their load time re-parsing the same handful of numeric shapes,
+//! so this walks the raw line bytes directly instead of collecting into
+//! intermediate `Vec<&str>`s.
+
+/// Reads a floating point number starting at byte offset `i`, returning the
+/// value and the offset just past it. Handles an optional sign, integer
+/// digits, an optional `.` with fractional digits, and an optional
+/// `e`/`E` exponent with its own sign.
+pub(crate) fn read_float(bytes: &[u8], mut i: usize) -> Option<(f32, usize)> {
+    let mut negative = false;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        negative = bytes[i] == b'-';
+        i += 1;
+    }
+
+    let mut res: f64 = 0.0;
+    let mut has_digits = false;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        res = res * 10.0 + (bytes[i] - b'0') as f64;
+        i += 1;
+        has_digits = true;
+    }
+
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        let mut scale = 0.1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            res += (bytes[i] - b'0') as f64 * scale;
+            scale *= 0.1;
+            i += 1;
+            has_digits = true;
+        }
+    }
+
+    if !has_digits {
+        return None;
+    }
+
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let (exp, next) = read_int(bytes, i + 1)?;
+        res *= 10f64.powi(exp as i32);
+        i = next;
+    }
+
+    if negative {
+        res = -res;
+    }
+    Some((res as f32, i))
+}
+
+/// Reads a signed integer starting at byte offset `i`: an optional sign
+/// followed by decimal digits, accumulated as `res = res * 10 + digit`.
+/// Returns `None` (rather than panicking) if the digits don't fit in an
+/// `i64`, so a pathologically long token turns into a parse error instead
+/// of aborting the process.
+pub(crate) fn read_int(bytes: &[u8], mut i: usize) -> Option<(i64, usize)> {
+    let mut negative = false;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        negative = bytes[i] == b'-';
+        i += 1;
+    }
+
+    let mut res: i64 = 0;
+    let mut has_digits = false;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        res = res
+            .checked_mul(10)
+            .and_then(|r| r.checked_add((bytes[i] - b'0') as i64))?;
+        i += 1;
+        has_digits = true;
+    }
+
+    if !has_digits {
+        return None;
+    }
+    Some((if negative { -res } else { res }, i))
+}
+
+fn skip_ws(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && (bytes[i] == b' ' || bytes[i] == b'\t') {
+        i += 1;
+    }
+    i
+}
+
+/// Walks a single trimmed OBJ line token by token without allocating.
+pub(crate) struct Tokenizer<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub(crate) fn new(line: &'a str) -> Self {
+        Tokenizer { bytes: line.as_bytes(), pos: 0 }
+    }
+
+    /// The next whitespace-delimited token, as a borrowed `&str`.
+    pub(crate) fn next_token(&mut self) -> Option<&'a str> {
+        self.pos = skip_ws(self.bytes, self.pos);
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+        let start = self.pos;
+        while self.pos < self.bytes.len() && !(self.bytes[self.pos] == b' ' || self.bytes[self.pos] == b'\t') {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos]).ok()
+    }
+
+    /// The next whitespace-delimited token, read directly as a float.
+    pub(crate) fn next_float(&mut self) -> Option<f32> {
+        self.pos = skip_ws(self.bytes, self.pos);
+        let (value, next) = read_float(self.bytes, self.pos)?;
+        self.pos = next;
+        Some(value)
+    }
+
+    /// Reads one face-vertex group (`v`, `v/vt`, `v//vn` or `v/vt/vn`),
+    /// returning the raw signed indices without resolving relative ones.
+    pub(crate) fn next_face_vertex(&mut self) -> Option<(i64, Option<i64>, Option<i64>)> {
+        self.pos = skip_ws(self.bytes, self.pos);
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+
+        let (v, mut pos) = read_int(self.bytes, self.pos)?;
+        let mut t = None;
+        let mut n = None;
+
+        if pos < self.bytes.len() && self.bytes[pos] == b'/' {
+            pos += 1;
+            if let Some((value, next)) = read_int(self.bytes, pos) {
+                t = Some(value);
+                pos = next;
+            }
+            if pos < self.bytes.len() && self.bytes[pos] == b'/' {
+                pos += 1;
+                if let Some((value, next)) = read_int(self.bytes, pos) {
+                    n = Some(value);
+                    pos = next;
+                }
+            }
+        }
+
+        self.pos = pos;
+        Some((v, t, n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_float_matches(s: &str) {
+        let expected: f32 = s.parse().unwrap();
+        let (actual, next) = read_float(s.as_bytes(), 0).unwrap();
+        assert_eq!(next, s.len());
+        assert!(
+            (actual - expected).abs() <= expected.abs() * 1e-5 + 1e-6,
+            "{} parsed as {} but std gave {}",
+            s,
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn float_matches_std_parse() {
+        for s in [
+            "0", "1", "-1", "3.14", "-3.14", "0.5", "-0.5", "123456.789", "+2.5", "1e3", "1E3",
+            "1.5e-2", "-1.5e+2", "0.0", "10", "-10",
+        ] {
+            assert_float_matches(s);
+        }
+    }
+
+    #[test]
+    fn int_matches_std_parse() {
+        for s in ["0", "1", "-1", "42", "-42", "+7", "1000000"] {
+            let expected: i64 = s.trim_start_matches('+').parse().unwrap();
+            let (actual, next) = read_int(s.as_bytes(), 0).unwrap();
+            assert_eq!(next, s.len());
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn tokenizer_splits_face_vertex_groups() {
+        let mut tok = Tokenizer::new("1/2/3 -4//5 6");
+        assert_eq!(tok.next_face_vertex(), Some((1, Some(2), Some(3))));
+        assert_eq!(tok.next_face_vertex(), Some((-4, None, Some(5))));
+        assert_eq!(tok.next_face_vertex(), Some((6, None, None)));
+        assert_eq!(tok.next_face_vertex(), None);
+    }
+
+    #[test]
+    fn read_int_rejects_overflow_instead_of_panicking() {
+        assert_eq!(read_int(b"-99999999999999999999", 0), None);
+        assert_eq!(read_int(b"99999999999999999999", 0), None);
+    }
+}
@@ -0,0 +1,464 @@
+pub mod bvh;
+pub mod export;
+mod fastparse;
+pub mod material;
+
+use fastparse::Tokenizer;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::Range;
+use std::path::Path;
+
+pub use bvh::{Aabb, Bvh, Hit};
+pub use material::Material;
+
+/// Everything that can go wrong while loading an OBJ file.
+#[derive(Debug)]
+pub enum ObjError {
+    /// Failure opening or reading the file itself.
+    Io(io::Error),
+    /// A `v`/`vn`/`vt`/`f` line didn't have the fields it needed.
+    Malformed { line: usize, message: String },
+    /// A face referenced a vertex/texture/normal index that doesn't exist.
+    BadIndex { line: usize, message: String },
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::Io(e) => write!(f, "error de E/S: {}", e),
+            ObjError::Malformed { line, message } => {
+                write!(f, "línea {} malformada: {}", line, message)
+            }
+            ObjError::BadIndex { line, message } => {
+                write!(f, "índice inválido en la línea {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+impl From<io::Error> for ObjError {
+    fn from(e: io::Error) -> Self {
+        ObjError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vertex {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Normal {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TextureCoord {
+    pub u: f32,
+    pub v: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Face {
+    pub vertex_indices: Vec<usize>,
+    pub normal_indices: Vec<usize>,
+    pub texture_indices: Vec<usize>,
+    pub material_index: Option<usize>,
+}
+
+/// A named object or group and the half-open range of `faces` it spans.
+#[derive(Debug, Clone)]
+pub struct NamedRange {
+    pub name: String,
+    pub faces: Range<usize>,
+}
+
+/// The fully parsed contents of an OBJ file (and the MTL libraries it references).
+#[derive(Debug)]
+pub struct Model {
+    pub vertices: Vec<Vertex>,
+    pub normals: Vec<Normal>,
+    pub texcoords: Vec<TextureCoord>,
+    pub faces: Vec<Face>,
+    pub materials: HashMap<String, Material>,
+    /// Material names in the order they were first `usemtl`'d, indexed by
+    /// `Face::material_index`.
+    pub material_names: Vec<String>,
+    pub mtllibs: Vec<String>,
+    pub objects: Vec<NamedRange>,
+    pub groups: Vec<NamedRange>,
+}
+
+impl Model {
+    /// A GPU-ready triangle index view over `faces`, fan-triangulating any
+    /// n-gons along the way.
+    pub fn triangles(&self) -> Vec<Triangle> {
+        self.faces
+            .iter()
+            .enumerate()
+            .flat_map(|(i, face)| face.triangles(i))
+            .collect()
+    }
+}
+
+fn parse_vertex(tok: &mut Tokenizer, line_num: usize) -> Result<Vertex, ObjError> {
+    let bad = || ObjError::Malformed {
+        line: line_num,
+        message: "se esperaban 3 componentes numéricos para 'v'".to_string(),
+    };
+    Ok(Vertex {
+        x: tok.next_float().ok_or_else(bad)?,
+        y: tok.next_float().ok_or_else(bad)?,
+        z: tok.next_float().ok_or_else(bad)?,
+    })
+}
+
+fn parse_normal(tok: &mut Tokenizer, line_num: usize) -> Result<Normal, ObjError> {
+    let bad = || ObjError::Malformed {
+        line: line_num,
+        message: "se esperaban 3 componentes numéricos para 'vn'".to_string(),
+    };
+    Ok(Normal {
+        x: tok.next_float().ok_or_else(bad)?,
+        y: tok.next_float().ok_or_else(bad)?,
+        z: tok.next_float().ok_or_else(bad)?,
+    })
+}
+
+fn parse_texture_coord(tok: &mut Tokenizer, line_num: usize) -> Result<TextureCoord, ObjError> {
+    let bad = || ObjError::Malformed {
+        line: line_num,
+        message: "se esperaban 2 componentes numéricos para 'vt'".to_string(),
+    };
+    Ok(TextureCoord {
+        u: tok.next_float().ok_or_else(bad)?,
+        v: tok.next_float().ok_or_else(bad)?,
+    })
+}
+
+/// Resolves a single OBJ face-vertex index component (the numbers around
+/// the `/` in e.g. `3/1/2`) to a 0-based index into the already-parsed
+/// `v`/`vt`/`vn` lists. Positive indices are 1-based from the start of the
+/// file; negative indices are relative, counting back from `count` (the
+/// number of entries defined so far).
+fn resolve_index(raw: i64, count: usize, line_num: usize, kind: &str) -> Result<usize, ObjError> {
+    if raw > 0 {
+        let index = raw as usize - 1;
+        if index < count {
+            Ok(index)
+        } else {
+            Err(ObjError::BadIndex {
+                line: line_num,
+                message: format!("índice de {} fuera de rango: {}", kind, raw),
+            })
+        }
+    } else if raw < 0 {
+        let k = (-raw) as usize;
+        count.checked_sub(k).ok_or_else(|| ObjError::BadIndex {
+            line: line_num,
+            message: format!("índice relativo de {} fuera de rango: {}", kind, raw),
+        })
+    } else {
+        Err(ObjError::BadIndex {
+            line: line_num,
+            message: "los índices OBJ son 1-based, 0 no es válido".to_string(),
+        })
+    }
+}
+
+fn parse_face(
+    tok: &mut Tokenizer,
+    line_num: usize,
+    vertex_count: usize,
+    texcoord_count: usize,
+    normal_count: usize,
+) -> Result<Face, ObjError> {
+    let mut vertex_indices = Vec::new();
+    let mut normal_indices = Vec::new();
+    let mut texture_indices = Vec::new();
+
+    while let Some((v, t, n)) = tok.next_face_vertex() {
+        vertex_indices.push(resolve_index(v, vertex_count, line_num, "vértice")?);
+        if let Some(t) = t {
+            texture_indices.push(resolve_index(t, texcoord_count, line_num, "textura")?);
+        }
+        if let Some(n) = n {
+            normal_indices.push(resolve_index(n, normal_count, line_num, "normal")?);
+        }
+    }
+
+    if vertex_indices.len() < 3 {
+        return Err(ObjError::Malformed {
+            line: line_num,
+            message: format!("una cara necesita al menos 3 vértices, se encontraron {}", vertex_indices.len()),
+        });
+    }
+
+    Ok(Face {
+        vertex_indices,
+        normal_indices,
+        texture_indices,
+        material_index: None,
+    })
+}
+
+/// One triangle produced by fan-triangulating a `Face`, carrying along the
+/// same per-corner texture/normal indices and material as the source face.
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle {
+    pub vertex_indices: [usize; 3],
+    pub texture_indices: [Option<usize>; 3],
+    pub normal_indices: [Option<usize>; 3],
+    pub material_index: Option<usize>,
+    pub face_index: usize,
+}
+
+impl Face {
+    /// Fan-triangulates this (possibly n-gon) face: `[v0,v1,...,vn-1]`
+    /// becomes `(v0,v1,v2), (v0,v2,v3), ...`. `face_index` is stamped onto
+    /// each resulting triangle so callers can trace a triangle back to the
+    /// face (and thus the material) it came from.
+    pub fn triangles(&self, face_index: usize) -> Vec<Triangle> {
+        let n = self.vertex_indices.len();
+        if n < 3 {
+            return Vec::new();
+        }
+
+        let texture_at = |i: usize| self.texture_indices.get(i).copied();
+        let normal_at = |i: usize| self.normal_indices.get(i).copied();
+
+        (1..n - 1)
+            .map(|i| Triangle {
+                vertex_indices: [self.vertex_indices[0], self.vertex_indices[i], self.vertex_indices[i + 1]],
+                texture_indices: [texture_at(0), texture_at(i), texture_at(i + 1)],
+                normal_indices: [normal_at(0), normal_at(i), normal_at(i + 1)],
+                material_index: self.material_index,
+                face_index,
+            })
+            .collect()
+    }
+}
+
+/// Loads and fully parses an OBJ file, resolving any `mtllib` it references
+/// relative to the OBJ's own directory.
+pub fn load_obj<P: AsRef<Path>>(path: P) -> Result<Model, ObjError> {
+    let path = path.as_ref();
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    let obj_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut vertices = Vec::new();
+    let mut normals = Vec::new();
+    let mut texcoords = Vec::new();
+    let mut faces = Vec::new();
+    let mut mtllibs = Vec::new();
+    let mut materials: HashMap<String, Material> = HashMap::new();
+    let mut material_names: Vec<String> = Vec::new();
+    let mut current_material_index: Option<usize> = None;
+
+    let mut objects = Vec::new();
+    let mut groups = Vec::new();
+    let mut current_object: Option<(String, usize)> = None;
+    let mut current_group: Option<(String, usize)> = None;
+
+    for (line_num, line_result) in reader.lines().enumerate() {
+        let line = line_result?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut tok = Tokenizer::new(line);
+        let tag = match tok.next_token() {
+            Some(tag) => tag,
+            None => continue,
+        };
+
+        match tag {
+            "v" => vertices.push(parse_vertex(&mut tok, line_num + 1)?),
+            "vn" => normals.push(parse_normal(&mut tok, line_num + 1)?),
+            "vt" => texcoords.push(parse_texture_coord(&mut tok, line_num + 1)?),
+            "f" => {
+                let mut face = parse_face(
+                    &mut tok,
+                    line_num + 1,
+                    vertices.len(),
+                    texcoords.len(),
+                    normals.len(),
+                )?;
+                face.material_index = current_material_index;
+                faces.push(face);
+            }
+            "usemtl" => {
+                if let Some(name) = tok.next_token() {
+                    let name = name.to_string();
+                    current_material_index = Some(
+                        material_names
+                            .iter()
+                            .position(|n| n == &name)
+                            .unwrap_or_else(|| {
+                                material_names.push(name);
+                                material_names.len() - 1
+                            }),
+                    );
+                }
+            }
+            "mtllib" => {
+                if let Some(lib_name) = tok.next_token() {
+                    let lib_name = lib_name.to_string();
+                    let mtl_path = obj_dir.join(&lib_name);
+                    materials.extend(material::parse_mtl(&mtl_path)?);
+                    mtllibs.push(lib_name);
+                }
+            }
+            "o" => {
+                if let Some(name) = tok.next_token() {
+                    if let Some((name, start)) = current_object.take() {
+                        objects.push(NamedRange { name, faces: start..faces.len() });
+                    }
+                    current_object = Some((name.to_string(), faces.len()));
+                }
+            }
+            "g" => {
+                if let Some(name) = tok.next_token() {
+                    if let Some((name, start)) = current_group.take() {
+                        groups.push(NamedRange { name, faces: start..faces.len() });
+                    }
+                    current_group = Some((name.to_string(), faces.len()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((name, start)) = current_object.take() {
+        objects.push(NamedRange { name, faces: start..faces.len() });
+    }
+    if let Some((name, start)) = current_group.take() {
+        groups.push(NamedRange { name, faces: start..faces.len() });
+    }
+
+    Ok(Model {
+        vertices,
+        normals,
+        texcoords,
+        faces,
+        materials,
+        material_names,
+        mtllibs,
+        objects,
+        groups,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write as _;
+
+    fn write_temp_obj(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let mut file = File::create(&path).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_vertices_and_faces_into_model() {
+        let path = write_temp_obj(
+            "lectura_obj_test_basic.obj",
+            "o square\n\
+             v 0 0 0\n\
+             v 1 0 0\n\
+             v 1 1 0\n\
+             v 0 1 0\n\
+             f 1 2 3 4\n",
+        );
+
+        let model = load_obj(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(model.vertices.len(), 4);
+        assert_eq!(model.faces.len(), 1);
+        assert_eq!(model.faces[0].vertex_indices, vec![0, 1, 2, 3]);
+        assert_eq!(model.objects.len(), 1);
+        assert_eq!(model.objects[0].name, "square");
+        assert_eq!(model.objects[0].faces, 0..1);
+    }
+
+    #[test]
+    fn malformed_vertex_line_is_reported_as_err() {
+        let path = write_temp_obj("lectura_obj_test_malformed.obj", "v 1 2\n");
+
+        let result = load_obj(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(ObjError::Malformed { line: 1, .. })));
+    }
+
+    #[test]
+    fn missing_file_is_reported_as_io_error() {
+        let result = load_obj("/nonexistent/path/does_not_exist.obj");
+        assert!(matches!(result, Err(ObjError::Io(_))));
+    }
+
+    #[test]
+    fn fan_triangulates_ngon_faces() {
+        let face = Face {
+            vertex_indices: vec![0, 1, 2, 3, 4],
+            normal_indices: Vec::new(),
+            texture_indices: Vec::new(),
+            material_index: None,
+        };
+
+        let triangles = face.triangles(7);
+        let corners: Vec<[usize; 3]> = triangles.iter().map(|t| t.vertex_indices).collect();
+        assert_eq!(corners, vec![[0, 1, 2], [0, 2, 3], [0, 3, 4]]);
+        assert!(triangles.iter().all(|t| t.face_index == 7));
+    }
+
+    #[test]
+    fn negative_indices_resolve_relative_to_vertices_defined_so_far() {
+        let path = write_temp_obj(
+            "lectura_obj_test_negative_index.obj",
+            "v 0 0 0\n\
+             v 1 0 0\n\
+             v 0 1 0\n\
+             f -3 -2 -1\n",
+        );
+
+        let model = load_obj(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // -3/-2/-1 with 3 vertices defined so far should resolve to 0/1/2,
+        // same as the equivalent "f 1 2 3".
+        assert_eq!(model.faces[0].vertex_indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn out_of_range_negative_index_is_reported_as_bad_index() {
+        let path = write_temp_obj(
+            "lectura_obj_test_negative_index_out_of_range.obj",
+            "v 0 0 0\n\
+             v 1 0 0\n\
+             v 0 1 0\n\
+             f -5 -2 -1\n",
+        );
+
+        let result = load_obj(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        // Only 3 vertices are defined so far, so -5 has no valid target.
+        assert!(matches!(result, Err(ObjError::BadIndex { .. })));
+    }
+}
@@ -0,0 +1,224 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::collections::HashMap;
+
+use crate::ObjError;
+
+#[derive(Debug, Clone)]
+pub struct Material {
+    pub name: String,
+    pub ambient: [f32; 3],
+    pub diffuse: [f32; 3],
+    pub specular: [f32; 3],
+    pub specular_exponent: f32,
+    pub dissolve: f32,
+    pub illum: u32,
+    pub map_kd: Option<String>,
+    pub map_ks: Option<String>,
+    pub map_bump: Option<String>,
+    pub map_d: Option<String>,
+}
+
+impl Material {
+    fn new(name: String) -> Self {
+        Material {
+            name,
+            ambient: [0.0, 0.0, 0.0],
+            diffuse: [0.0, 0.0, 0.0],
+            specular: [0.0, 0.0, 0.0],
+            specular_exponent: 0.0,
+            dissolve: 1.0,
+            illum: 0,
+            map_kd: None,
+            map_ks: None,
+            map_bump: None,
+            map_d: None,
+        }
+    }
+}
+
+fn parse_vec3(parts: &[&str]) -> Option<[f32; 3]> {
+    if parts.len() >= 4 {
+        Some([
+            parts[1].parse().ok()?,
+            parts[2].parse().ok()?,
+            parts[3].parse().ok()?,
+        ])
+    } else {
+        None
+    }
+}
+
+/// Parses a single `.mtl` file into its named materials, keyed by the
+/// name given after `newmtl`. `mtl_path` is resolved by the caller
+/// (typically relative to the directory the referencing OBJ lives in).
+pub fn parse_mtl<P: AsRef<Path>>(mtl_path: P) -> Result<HashMap<String, Material>, ObjError> {
+    let mut materials = HashMap::new();
+    let mut current: Option<Material> = None;
+
+    let file = File::open(&mtl_path)?;
+    let reader = BufReader::new(file);
+
+    for line_result in reader.lines() {
+        let line = line_result?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.is_empty() {
+            continue;
+        }
+
+        match parts[0] {
+            "newmtl" => {
+                if let Some(finished) = current.take() {
+                    materials.insert(finished.name.clone(), finished);
+                }
+                if parts.len() > 1 {
+                    current = Some(Material::new(parts[1].to_string()));
+                }
+            }
+            "Ka" => {
+                if let Some(mat) = current.as_mut() {
+                    if let Some(v) = parse_vec3(&parts) {
+                        mat.ambient = v;
+                    }
+                }
+            }
+            "Kd" => {
+                if let Some(mat) = current.as_mut() {
+                    if let Some(v) = parse_vec3(&parts) {
+                        mat.diffuse = v;
+                    }
+                }
+            }
+            "Ks" => {
+                if let Some(mat) = current.as_mut() {
+                    if let Some(v) = parse_vec3(&parts) {
+                        mat.specular = v;
+                    }
+                }
+            }
+            "Ns" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        if let Ok(n) = parts[1].parse() {
+                            mat.specular_exponent = n;
+                        }
+                    }
+                }
+            }
+            "d" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        if let Ok(d) = parts[1].parse() {
+                            mat.dissolve = d;
+                        }
+                    }
+                }
+            }
+            "Tr" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        if let Ok(tr) = parts[1].parse::<f32>() {
+                            mat.dissolve = 1.0 - tr;
+                        }
+                    }
+                }
+            }
+            "illum" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        if let Ok(i) = parts[1].parse() {
+                            mat.illum = i;
+                        }
+                    }
+                }
+            }
+            "map_Kd" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        mat.map_kd = Some(parts[1..].join(" "));
+                    }
+                }
+            }
+            "map_Ks" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        mat.map_ks = Some(parts[1..].join(" "));
+                    }
+                }
+            }
+            "map_Bump" | "bump" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        mat.map_bump = Some(parts[1..].join(" "));
+                    }
+                }
+            }
+            "map_d" => {
+                if let Some(mat) = current.as_mut() {
+                    if parts.len() > 1 {
+                        mat.map_d = Some(parts[1..].join(" "));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(finished) = current.take() {
+        materials.insert(finished.name.clone(), finished);
+    }
+
+    Ok(materials)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write as _;
+
+    fn write_temp_mtl(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let mut file = File::create(&path).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn parses_material_properties() {
+        let path = write_temp_mtl(
+            "lectura_obj_test_material.mtl",
+            "newmtl skin\n\
+             Ka 0.1 0.2 0.3\n\
+             Kd 0.4 0.5 0.6\n\
+             Ks 0.7 0.8 0.9\n\
+             Ns 32.0\n\
+             d 0.5\n\
+             illum 2\n\
+             map_Kd textures/skin.png\n",
+        );
+
+        let materials = parse_mtl(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mat = materials.get("skin").expect("material 'skin' should be parsed");
+        assert_eq!(mat.ambient, [0.1, 0.2, 0.3]);
+        assert_eq!(mat.diffuse, [0.4, 0.5, 0.6]);
+        assert_eq!(mat.specular, [0.7, 0.8, 0.9]);
+        assert_eq!(mat.specular_exponent, 32.0);
+        assert_eq!(mat.dissolve, 0.5);
+        assert_eq!(mat.illum, 2);
+        assert_eq!(mat.map_kd.as_deref(), Some("textures/skin.png"));
+    }
+
+    #[test]
+    fn missing_file_returns_io_error() {
+        let result = parse_mtl("/nonexistent/path/does_not_exist.mtl");
+        assert!(matches!(result, Err(ObjError::Io(_))));
+    }
+}